@@ -36,6 +36,17 @@ pub enum ASN1Class {
 pub enum ASN1Block {
     Boolean(usize, bool),
     Integer(usize, BigInt),
+    /// The ASN.1 REAL type (tag 9).
+    ///
+    /// Stored as a native `f64`, which loses precision relative to the
+    /// arbitrary-precision mantissa/exponent that X.690 allows, but is
+    /// sufficient for the values this crate encounters in practice.
+    Real(usize, f64),
+    /// The ASN.1 ENUMERATED type (tag 10).
+    ///
+    /// Decodes and encodes exactly like [`ASN1Block::Integer`], but keeps
+    /// its own universal tag so round-tripping preserves the distinction.
+    Enumerated(usize, BigInt),
     BitString(usize, usize, Vec<u8>),
     OctetString(usize, Vec<u8>),
     Null(usize),
@@ -44,6 +55,20 @@ pub enum ASN1Block {
     PrintableString(usize, String),
     TeletexString(usize, String),
     IA5String(usize, String),
+    /// ObjectDescriptor (tag 7): a GraphicString-valued human-readable
+    /// description of an object.
+    ObjectDescriptor(usize, String),
+    /// VideotexString (tag 21). Unlike the other string variants, its
+    /// content isn't interpreted as text here since it uses the CCITT
+    /// videotex character set rather than anything `String` can represent
+    /// directly.
+    VideotexString(usize, Vec<u8>),
+    /// GraphicString (tag 25).
+    GraphicString(usize, String),
+    /// VisibleString / ISO646String (tag 26).
+    VisibleString(usize, String),
+    /// GeneralString (tag 27).
+    GeneralString(usize, String),
     UTCTime(usize, PrimitiveDateTime),
     GeneralizedTime(usize, PrimitiveDateTime),
     UniversalString(usize, String),
@@ -71,6 +96,8 @@ impl ASN1Block {
         match *self {
             ASN1Block::Boolean(_, _) => ASN1Class::Universal,
             ASN1Block::Integer(_, _) => ASN1Class::Universal,
+            ASN1Block::Real(_, _) => ASN1Class::Universal,
+            ASN1Block::Enumerated(_, _) => ASN1Class::Universal,
             ASN1Block::BitString(_, _, _) => ASN1Class::Universal,
             ASN1Block::OctetString(_, _) => ASN1Class::Universal,
             ASN1Block::Null(_) => ASN1Class::Universal,
@@ -79,6 +106,11 @@ impl ASN1Block {
             ASN1Block::PrintableString(_, _) => ASN1Class::Universal,
             ASN1Block::TeletexString(_, _) => ASN1Class::Universal,
             ASN1Block::IA5String(_, _) => ASN1Class::Universal,
+            ASN1Block::ObjectDescriptor(_, _) => ASN1Class::Universal,
+            ASN1Block::VideotexString(_, _) => ASN1Class::Universal,
+            ASN1Block::GraphicString(_, _) => ASN1Class::Universal,
+            ASN1Block::VisibleString(_, _) => ASN1Class::Universal,
+            ASN1Block::GeneralString(_, _) => ASN1Class::Universal,
             ASN1Block::UTCTime(_, _) => ASN1Class::Universal,
             ASN1Block::GeneralizedTime(_, _) => ASN1Class::Universal,
             ASN1Block::UniversalString(_, _) => ASN1Class::Universal,
@@ -95,6 +127,8 @@ impl ASN1Block {
         match *self {
             ASN1Block::Boolean(o, _) => o,
             ASN1Block::Integer(o, _) => o,
+            ASN1Block::Real(o, _) => o,
+            ASN1Block::Enumerated(o, _) => o,
             ASN1Block::BitString(o, _, _) => o,
             ASN1Block::OctetString(o, _) => o,
             ASN1Block::Null(o) => o,
@@ -103,6 +137,11 @@ impl ASN1Block {
             ASN1Block::PrintableString(o, _) => o,
             ASN1Block::TeletexString(o, _) => o,
             ASN1Block::IA5String(o, _) => o,
+            ASN1Block::ObjectDescriptor(o, _) => o,
+            ASN1Block::VideotexString(o, _) => o,
+            ASN1Block::GraphicString(o, _) => o,
+            ASN1Block::VisibleString(o, _) => o,
+            ASN1Block::GeneralString(o, _) => o,
             ASN1Block::UTCTime(o, _) => o,
             ASN1Block::GeneralizedTime(o, _) => o,
             ASN1Block::UniversalString(o, _) => o,
@@ -120,6 +159,8 @@ impl PartialEq for ASN1Block {
         match (self, other) {
             (&ASN1Block::Boolean(_, a1), &ASN1Block::Boolean(_, a2)) => a1 == a2,
             (&ASN1Block::Integer(_, ref a1), &ASN1Block::Integer(_, ref a2)) => a1 == a2,
+            (&ASN1Block::Real(_, a1), &ASN1Block::Real(_, a2)) => a1 == a2,
+            (&ASN1Block::Enumerated(_, ref a1), &ASN1Block::Enumerated(_, ref a2)) => a1 == a2,
             (&ASN1Block::BitString(_, a1, ref b1), &ASN1Block::BitString(_, a2, ref b2)) => {
                 (a1 == a2) && (b1 == b2)
             }
@@ -136,6 +177,21 @@ impl PartialEq for ASN1Block {
                 a1 == a2
             }
             (&ASN1Block::IA5String(_, ref a1), &ASN1Block::IA5String(_, ref a2)) => a1 == a2,
+            (&ASN1Block::ObjectDescriptor(_, ref a1), &ASN1Block::ObjectDescriptor(_, ref a2)) => {
+                a1 == a2
+            }
+            (&ASN1Block::VideotexString(_, ref a1), &ASN1Block::VideotexString(_, ref a2)) => {
+                a1 == a2
+            }
+            (&ASN1Block::GraphicString(_, ref a1), &ASN1Block::GraphicString(_, ref a2)) => {
+                a1 == a2
+            }
+            (&ASN1Block::VisibleString(_, ref a1), &ASN1Block::VisibleString(_, ref a2)) => {
+                a1 == a2
+            }
+            (&ASN1Block::GeneralString(_, ref a1), &ASN1Block::GeneralString(_, ref a2)) => {
+                a1 == a2
+            }
             (&ASN1Block::UTCTime(_, ref a1), &ASN1Block::UTCTime(_, ref a2)) => a1 == a2,
             (&ASN1Block::GeneralizedTime(_, ref a1), &ASN1Block::GeneralizedTime(_, ref a2)) => {
                 a1 == a2
@@ -158,4 +214,3 @@ impl PartialEq for ASN1Block {
         }
     }
 }
-
@@ -1,10 +1,13 @@
-use num_bigint::BigUint;
-use num_traits::Zero;
-use crate::size_of;
-
-use crate::{asn1_data_types::ASN1Class, errors::ASN1DecodeErr};
+use std::str::FromStr;
 
+use crate::size_of;
+use num_bigint::{BigInt, BigUint};
+use num_traits::Zero;
 
+use crate::{
+    asn1_data_types::{ASN1Block, ASN1Class},
+    errors::ASN1DecodeErr,
+};
 
 /// Returns the tag, if the type is constructed and the class.
 pub fn tag(i: &[u8], index: &mut usize) -> Result<(BigUint, bool, ASN1Class), ASN1DecodeErr> {
@@ -26,7 +29,6 @@ pub fn tag(i: &[u8], index: &mut usize) -> Result<(BigUint, bool, ASN1Class), AS
     }
 }
 
-
 pub fn base127(i: &[u8], index: &mut usize) -> Result<BigUint, ASN1DecodeErr> {
     let mut res = BigUint::zero();
 
@@ -45,8 +47,77 @@ pub fn base127(i: &[u8], index: &mut usize) -> Result<BigUint, ASN1DecodeErr> {
     }
 }
 
+/// The length of an ASN.1 TLV's content, in either BER form.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Length {
+    /// A definite-form length: the content is exactly this many octets.
+    Definite(usize),
+    /// A BER indefinite-form length (length octet `0x80`). Only valid on
+    /// constructed blocks; the content runs until an end-of-content marker
+    /// (tag `0x00`, length `0x00`) is reached.
+    Indefinite,
+}
+
+/// Selects how strictly the decoder enforces DER canonicality versus how
+/// much BER generality it tolerates.
+///
+/// `Der` is what you want when verifying a certificate or anything else
+/// whose hash/signature depends on a unique byte representation: lengths
+/// must use the minimal definite form, booleans must be exactly `0x00` or
+/// `0xff`, indefinite lengths are rejected, and `Set`/`SetOf` elements must
+/// already be in canonical order. `Ber` accepts the fuller generality that
+/// real-world peers emit (non-minimal lengths, any non-zero boolean byte,
+/// indefinite lengths, unordered sets) and is the right choice when the
+/// goal is simply to read whatever was sent.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DecodeMode {
+    Der,
+    Ber,
+}
+
+/// Decode the single content octet of a BOOLEAN.
+///
+/// `Der` requires it to be exactly `0x00` (false) or `0xff` (true) and
+/// rejects anything else; `Ber` is lenient and treats any non-zero octet
+/// as `true`, matching real-world encoders that don't normalize to `0xff`.
+pub fn boolean(content: &[u8], mode: DecodeMode) -> Result<bool, ASN1DecodeErr> {
+    if content.len() != 1 {
+        return Err(ASN1DecodeErr::BadBooleanLength(content.len()));
+    }
+
+    let byte = content[0];
+    if mode == DecodeMode::Der && byte != 0x00 && byte != 0xff {
+        return Err(ASN1DecodeErr::InvalidBooleanValue(byte));
+    }
 
-pub fn length(i: &[u8], index: &mut usize) -> Result<usize, ASN1DecodeErr> {
+    Ok(byte != 0x00)
+}
+
+/// Check that a decoded SET OF's children already appear in the DER
+/// canonical order (X.690 §11.6): sorted by their full encoded TLV bytes,
+/// with a shorter value that's a prefix of a longer one sorting first.
+///
+/// `Der` mode rejects a set that isn't already in that order; `Ber` mode
+/// never checks, since BER doesn't constrain SET OF ordering at all.
+pub fn check_set_order(children: &[Vec<u8>], mode: DecodeMode) -> Result<(), ASN1DecodeErr> {
+    if mode == DecodeMode::Ber {
+        return Ok(());
+    }
+
+    let is_sorted = children.windows(2).all(|pair| pair[0] <= pair[1]);
+    if is_sorted {
+        Ok(())
+    } else {
+        Err(ASN1DecodeErr::SetNotInCanonicalOrder)
+    }
+}
+
+pub fn length(
+    i: &[u8],
+    index: &mut usize,
+    constructed: bool,
+    mode: DecodeMode,
+) -> Result<Length, ASN1DecodeErr> {
     if *index >= i.len() {
         return Err(ASN1DecodeErr::Incomplete);
     }
@@ -57,9 +128,22 @@ pub fn length(i: &[u8], index: &mut usize) -> Result<usize, ASN1DecodeErr> {
     // things that big. So we're boring, and only accept lengths
     // that fit within a usize.
     *index += 1;
+
+    if startbyte == 0x80 {
+        if !constructed {
+            return Err(ASN1DecodeErr::IndefiniteInPrimitive);
+        }
+        if mode == DecodeMode::Der {
+            return Err(ASN1DecodeErr::IndefiniteLengthInDer);
+        }
+        return Ok(Length::Indefinite);
+    }
+
     if startbyte >= 0x80 {
-        let mut lenlen = (startbyte & 0x7f) as usize;
+        let start_lenlen = (startbyte & 0x7f) as usize;
+        let mut lenlen = start_lenlen;
         let mut res = 0;
+        let mut first_len_byte = None;
 
         if lenlen > size_of::<usize>() {
             return Err(ASN1DecodeErr::LengthTooLarge(lenlen));
@@ -70,18 +154,222 @@ pub fn length(i: &[u8], index: &mut usize) -> Result<usize, ASN1DecodeErr> {
                 return Err(ASN1DecodeErr::Incomplete);
             }
 
-            res = (res << 8) + (i[*index] as usize);
+            let byte = i[*index];
+            if first_len_byte.is_none() {
+                first_len_byte = Some(byte);
+            }
+            res = (res << 8) + (byte as usize);
 
             *index += 1;
             lenlen -= 1;
         }
 
-        Ok(res)
+        if mode == DecodeMode::Der {
+            // DER requires the minimal number of length octets: no
+            // leading zero octet, and the short form whenever the value
+            // fits in it.
+            let non_minimal = res < 128 || (start_lenlen > 1 && first_len_byte == Some(0));
+            if non_minimal {
+                return Err(ASN1DecodeErr::NonMinimalLength(res));
+            }
+        }
+
+        Ok(Length::Definite(res))
     } else {
-        Ok(startbyte as usize)
+        Ok(Length::Definite(startbyte as usize))
+    }
+}
+
+/// Returns `true` if the given tag/length pair is the BER end-of-content
+/// marker (universal tag `0`, primitive, length `0`) that terminates an
+/// indefinite-length constructed value. The constructed-block parser
+/// should check this after decoding each child's class/constructed/tag and
+/// length while unwinding an indefinite-length value, and stop (without
+/// treating the marker as a child block) once it matches.
+///
+/// All of class, constructed and tag must match: a context-specific,
+/// application or private `[0]`, or a constructed `[UNIVERSAL 0]`, is a
+/// perfectly ordinary (if unusual) block and must not be mistaken for EOC.
+pub fn is_eoc(class: ASN1Class, constructed: bool, t: &BigUint, l: Length) -> bool {
+    class == ASN1Class::Universal && !constructed && t.is_zero() && l == Length::Definite(0)
+}
+
+/// Decode the content octets of a two's-complement big integer, as used by
+/// both INTEGER (tag 2) and ENUMERATED (tag 10).
+///
+/// Rejects non-minimal padding: a leading `0x00` is only allowed when the
+/// following bit would otherwise be read as a sign bit, and likewise for a
+/// leading `0xff` with a clear following bit. Both are redundant encodings
+/// of the same value and are not valid DER/BER.
+pub fn twos_complement_integer(content: &[u8]) -> Result<BigInt, ASN1DecodeErr> {
+    if content.is_empty() {
+        return Err(ASN1DecodeErr::Incomplete);
+    }
+
+    if content.len() > 1 {
+        let redundant = (content[0] == 0x00 && (content[1] & 0x80) == 0)
+            || (content[0] == 0xff && (content[1] & 0x80) != 0);
+        if redundant {
+            return Err(ASN1DecodeErr::InvalidIntegerPadding);
+        }
+    }
+
+    Ok(BigInt::from_signed_bytes_be(content))
+}
+
+/// Decode the content octets of an INTEGER (tag 2).
+pub fn integer(content: &[u8]) -> Result<BigInt, ASN1DecodeErr> {
+    twos_complement_integer(content)
+}
+
+/// Decode the content octets of an ENUMERATED (tag 10). Identical to
+/// [`integer`], since X.690 defines ENUMERATED as sharing INTEGER's
+/// content encoding under its own universal tag.
+pub fn enumerated(content: &[u8]) -> Result<BigInt, ASN1DecodeErr> {
+    twos_complement_integer(content)
+}
+
+/// Decode the content octets of a restricted-character-set string
+/// (`ObjectDescriptor`, `GraphicString`, `VisibleString`, `GeneralString`)
+/// into a `String`. These character sets are always representable
+/// byte-for-byte, the same way [`crate::encode::asn1_string`]'s
+/// `force_chars` path encodes them.
+pub fn restricted_string(content: &[u8]) -> String {
+    content.iter().map(|&b| b as char).collect()
+}
+
+/// Build the `ASN1Block` for one of the universal tags this crate only
+/// supports as a restricted string or raw-byte string (`ObjectDescriptor`
+/// 7, `VideotexString` 21, `GraphicString` 25, `VisibleString` 26,
+/// `GeneralString` 27), or `None` if `universal_tag` isn't one of those.
+pub fn string_like_block(offset: usize, universal_tag: u8, content: &[u8]) -> Option<ASN1Block> {
+    match universal_tag {
+        7 => Some(ASN1Block::ObjectDescriptor(
+            offset,
+            restricted_string(content),
+        )),
+        21 => Some(ASN1Block::VideotexString(offset, content.to_vec())),
+        25 => Some(ASN1Block::GraphicString(offset, restricted_string(content))),
+        26 => Some(ASN1Block::VisibleString(offset, restricted_string(content))),
+        27 => Some(ASN1Block::GeneralString(offset, restricted_string(content))),
+        _ => None,
     }
 }
 
+/// Decode the content octets of an ASN.1 REAL (X.690 §8.5) into an `f64`.
+///
+/// An empty content is +0.0. Otherwise the first octet selects the
+/// encoding: bit 8 set means binary, bits 8-7 `00` means a decimal
+/// (ISO 6093) encoding carried as text in the remaining octets, and bits
+/// 8-7 `01` means one of the special values (+INF, -INF, NaN, -0.0).
+pub fn real(content: &[u8]) -> Result<f64, ASN1DecodeErr> {
+    let first = match content.first() {
+        None => return Ok(0.0),
+        Some(b) => *b,
+    };
+
+    if (first & 0x80) != 0 {
+        binary_real(first, &content[1..])
+    } else if (first & 0xc0) == 0x40 {
+        match first {
+            0x40 => Ok(f64::INFINITY),
+            0x41 => Ok(f64::NEG_INFINITY),
+            0x42 => Ok(f64::NAN),
+            0x43 => Ok(-0.0),
+            _ => Err(ASN1DecodeErr::InvalidRealEncoding(format!(
+                "unrecognized special real value {:#04x}",
+                first
+            ))),
+        }
+    } else if (first & 0xc0) == 0x00 {
+        let text = std::str::from_utf8(&content[1..]).map_err(|_| {
+            ASN1DecodeErr::InvalidRealEncoding("decimal real is not valid UTF-8".to_string())
+        })?;
+        f64::from_str(text.trim())
+            .map_err(|_| ASN1DecodeErr::InvalidRealEncoding(format!("bad decimal real: {}", text)))
+    } else {
+        Err(ASN1DecodeErr::InvalidRealEncoding(format!(
+            "reserved real encoding in first octet {:#04x}",
+            first
+        )))
+    }
+}
+
+fn binary_real(first: u8, rest: &[u8]) -> Result<f64, ASN1DecodeErr> {
+    let sign = if (first & 0x40) != 0 { -1.0 } else { 1.0 };
+    let base: f64 = match (first >> 4) & 0x3 {
+        0b00 => 2.0,
+        0b01 => 8.0,
+        0b10 => 16.0,
+        _ => {
+            return Err(ASN1DecodeErr::InvalidRealEncoding(
+                "base 2^F binary reals are not supported".to_string(),
+            ))
+        }
+    };
+    let scale = i32::from((first >> 2) & 0x3);
+
+    let mut index = 0;
+    let explen = match first & 0x3 {
+        0b00 => 1,
+        0b01 => 2,
+        0b10 => 3,
+        _ => {
+            let n = *rest.first().ok_or(ASN1DecodeErr::InvalidRealEncoding(
+                "missing real exponent length octet".to_string(),
+            ))? as usize;
+            index += 1;
+            n
+        }
+    };
+
+    if rest.len() < index + explen || explen == 0 {
+        return Err(ASN1DecodeErr::InvalidRealEncoding(
+            "truncated real exponent".to_string(),
+        ));
+    }
+
+    let mut exponent: i64 = if (rest[index] & 0x80) != 0 { -1 } else { 0 };
+    for &byte in &rest[index..index + explen] {
+        exponent = (exponent << 8) | i64::from(byte);
+    }
+    index += explen;
+
+    let mantissa_bytes = &rest[index..];
+    if mantissa_bytes.is_empty() {
+        return Err(ASN1DecodeErr::InvalidRealEncoding(
+            "missing real mantissa".to_string(),
+        ));
+    }
+    let mut mantissa = 0f64;
+    for &byte in mantissa_bytes {
+        mantissa = mantissa * 256.0 + f64::from(byte);
+    }
+
+    let scaled = scale_by_power(mantissa, 2.0, i64::from(scale));
+    Ok(sign * scale_by_power(scaled, base, exponent))
+}
+
+/// Multiply `value` by `base.powi(exponent)`, without the precision loss
+/// (and outright underflow to zero) that a single `powi` call suffers for
+/// large negative exponents landing in `f64`'s subnormal range: `powi`
+/// computes its result via repeated squaring, and the intermediate
+/// denormalized products it builds up along the way can lose all their
+/// precision before the final result is reached, even when that final
+/// result is perfectly representable. Applying the exponent in bounded
+/// chunks keeps every intermediate power within the normal range, so only
+/// the last multiplication — the one that's actually supposed to round
+/// into subnormal territory — does so.
+fn scale_by_power(mut value: f64, base: f64, mut exponent: i64) -> f64 {
+    // 240 keeps even the largest supported base in range: 16^240 = 2^960.
+    const CHUNK: i64 = 240;
+    while exponent != 0 {
+        let step = exponent.clamp(-CHUNK, CHUNK);
+        value *= base.powi(step as i32);
+        exponent -= step;
+    }
+    value
+}
 
 pub fn class(i: u8) -> Result<ASN1Class, ASN1DecodeErr> {
     match i >> 6 {
@@ -92,3 +380,169 @@ pub fn class(i: u8) -> Result<ASN1Class, ASN1DecodeErr> {
         _ => Err(ASN1DecodeErr::InvalidClass(i)),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encode;
+
+    fn decode_real_tlv(bytes: &[u8]) -> f64 {
+        let mut index = 0;
+        let (_tag, constructed, _class) = tag(bytes, &mut index).unwrap();
+        let content_len = match length(bytes, &mut index, constructed, DecodeMode::Ber).unwrap() {
+            Length::Definite(n) => n,
+            Length::Indefinite => panic!("REAL is never indefinite-length"),
+        };
+        real(&bytes[index..index + content_len]).unwrap()
+    }
+
+    #[test]
+    fn real_round_trips_special_values() {
+        for v in [0.0f64, -0.0, f64::INFINITY, f64::NEG_INFINITY, f64::NAN] {
+            let encoded = encode::asn1_real(ASN1Class::Universal, v).unwrap();
+            let decoded = decode_real_tlv(&encoded);
+            if v.is_nan() {
+                assert!(decoded.is_nan());
+            } else {
+                assert_eq!(decoded.to_bits(), v.to_bits());
+            }
+        }
+    }
+
+    #[test]
+    fn real_round_trips_binary_values_including_subnormals() {
+        let values = [
+            1.0f64,
+            -1.0,
+            1.5,
+            123_456.789,
+            f64::MIN_POSITIVE,
+            f64::from_bits(1), // smallest positive subnormal, 5e-324
+            f64::MAX,
+            -f64::MAX,
+        ];
+        for v in values {
+            let encoded = encode::asn1_real(ASN1Class::Universal, v).unwrap();
+            let decoded = decode_real_tlv(&encoded);
+            assert_eq!(
+                decoded.to_bits(),
+                v.to_bits(),
+                "round trip failed for {:e}",
+                v
+            );
+        }
+    }
+
+    #[test]
+    fn is_eoc_only_matches_universal_primitive_tag_zero() {
+        let zero = BigUint::zero();
+        let one = BigUint::from(1u8);
+
+        assert!(is_eoc(
+            ASN1Class::Universal,
+            false,
+            &zero,
+            Length::Definite(0)
+        ));
+
+        // A context-specific [0] primitive with empty content is an
+        // ordinary block (e.g. an implicit-tagged optional field), not EOC.
+        assert!(!is_eoc(
+            ASN1Class::ContextSpecific,
+            false,
+            &zero,
+            Length::Definite(0)
+        ));
+        // Nor is a constructed universal tag 0.
+        assert!(!is_eoc(
+            ASN1Class::Universal,
+            true,
+            &zero,
+            Length::Definite(0)
+        ));
+        // Nor a non-zero tag.
+        assert!(!is_eoc(
+            ASN1Class::Universal,
+            false,
+            &one,
+            Length::Definite(0)
+        ));
+        // Nor a non-empty content.
+        assert!(!is_eoc(
+            ASN1Class::Universal,
+            false,
+            &zero,
+            Length::Definite(1)
+        ));
+    }
+
+    #[test]
+    fn enumerated_round_trips_and_keeps_its_own_tag() {
+        for v in [
+            BigInt::from(0),
+            BigInt::from(-1),
+            BigInt::from(127),
+            BigInt::from(-128),
+            BigInt::from(128),
+        ] {
+            let encoded = encode::asn1_enumerated(ASN1Class::Universal, &v);
+            assert_eq!(
+                encoded[0], 10,
+                "ENUMERATED must keep tag 10, not INTEGER's 2"
+            );
+
+            let mut index = 0;
+            let (t, constructed, _class) = tag(&encoded, &mut index).unwrap();
+            assert_eq!(t, BigUint::from(10u8));
+            let content_len =
+                match length(&encoded, &mut index, constructed, DecodeMode::Der).unwrap() {
+                    Length::Definite(n) => n,
+                    Length::Indefinite => panic!("ENUMERATED is never indefinite-length"),
+                };
+            let decoded = enumerated(&encoded[index..index + content_len]).unwrap();
+            assert_eq!(decoded, v);
+        }
+    }
+
+    #[test]
+    fn enumerated_rejects_non_minimal_padding() {
+        // 0x00 0x01 is a redundant leading zero: 1 alone already has a clear
+        // sign bit, so the padding isn't needed.
+        assert_eq!(
+            enumerated(&[0x00, 0x01]),
+            Err(ASN1DecodeErr::InvalidIntegerPadding)
+        );
+        // 0xff 0x80 is likewise a redundant leading 0xff.
+        assert_eq!(
+            enumerated(&[0xff, 0x80]),
+            Err(ASN1DecodeErr::InvalidIntegerPadding)
+        );
+    }
+
+    #[test]
+    fn boolean_der_requires_canonical_bytes_but_ber_accepts_any_nonzero() {
+        assert_eq!(boolean(&[0x00], DecodeMode::Der), Ok(false));
+        assert_eq!(boolean(&[0xff], DecodeMode::Der), Ok(true));
+        assert_eq!(
+            boolean(&[0x01], DecodeMode::Der),
+            Err(ASN1DecodeErr::InvalidBooleanValue(0x01))
+        );
+
+        assert_eq!(boolean(&[0x00], DecodeMode::Ber), Ok(false));
+        assert_eq!(boolean(&[0xff], DecodeMode::Ber), Ok(true));
+        assert_eq!(boolean(&[0x01], DecodeMode::Ber), Ok(true));
+    }
+
+    #[test]
+    fn check_set_order_is_enforced_only_in_der_mode() {
+        let sorted = vec![vec![0x01], vec![0x01, 0x00], vec![0x02]];
+        let unsorted = vec![vec![0x02], vec![0x01]];
+
+        assert_eq!(check_set_order(&sorted, DecodeMode::Der), Ok(()));
+        assert_eq!(
+            check_set_order(&unsorted, DecodeMode::Der),
+            Err(ASN1DecodeErr::SetNotInCanonicalOrder)
+        );
+        assert_eq!(check_set_order(&unsorted, DecodeMode::Ber), Ok(()));
+    }
+}
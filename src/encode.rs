@@ -1,8 +1,33 @@
-use num_bigint::BigUint;
-use num_traits::{FromPrimitive, Zero, ToPrimitive};
+use num_bigint::{BigInt, BigUint};
+use num_traits::{FromPrimitive, ToPrimitive, Zero};
 
 use crate::{asn1_data_types::ASN1Class, errors::ASN1EncodeErr};
 
+/// Encode a two's-complement big integer TLV under the given universal
+/// tag, as used by both INTEGER (tag 2) and ENUMERATED (tag 10).
+/// `BigInt::to_signed_bytes_be` already produces the minimal two's
+/// complement form, so the result round-trips through
+/// `decode::twos_complement_integer`'s non-minimal-padding check.
+pub(crate) fn asn1_bigint(universal_tag: u8, c: ASN1Class, v: &BigInt) -> Vec<u8> {
+    let mut body = v.to_signed_bytes_be();
+    let inttag = BigUint::from_u8(universal_tag).unwrap();
+    let mut lenbytes = len(body.len());
+    let mut tagbytes = tag(c, false, &inttag);
+
+    let mut res = Vec::new();
+    res.append(&mut tagbytes);
+    res.append(&mut lenbytes);
+    res.append(&mut body);
+    res
+}
+
+pub(crate) fn asn1_integer(c: ASN1Class, v: &BigInt) -> Vec<u8> {
+    asn1_bigint(2, c, v)
+}
+
+pub(crate) fn asn1_enumerated(c: ASN1Class, v: &BigInt) -> Vec<u8> {
+    asn1_bigint(10, c, v)
+}
 
 pub(crate) fn asn1_string(
     tag: u8,
@@ -33,6 +58,145 @@ pub(crate) fn asn1_string(
     Ok(res)
 }
 
+/// Encode an `ObjectDescriptor` (tag 7). Its content is GraphicString text,
+/// which (like `PrintableString`/`IA5String`) is always representable
+/// byte-for-byte, so this goes through [`asn1_string`] with `force_chars`.
+pub(crate) fn asn1_object_descriptor(c: ASN1Class, s: &str) -> Result<Vec<u8>, ASN1EncodeErr> {
+    asn1_string(7, true, c, s)
+}
+
+/// Encode a `GraphicString` (tag 25).
+pub(crate) fn asn1_graphic_string(c: ASN1Class, s: &str) -> Result<Vec<u8>, ASN1EncodeErr> {
+    asn1_string(25, true, c, s)
+}
+
+/// Encode a `VisibleString`/`ISO646String` (tag 26).
+pub(crate) fn asn1_visible_string(c: ASN1Class, s: &str) -> Result<Vec<u8>, ASN1EncodeErr> {
+    asn1_string(26, true, c, s)
+}
+
+/// Encode a `GeneralString` (tag 27).
+pub(crate) fn asn1_general_string(c: ASN1Class, s: &str) -> Result<Vec<u8>, ASN1EncodeErr> {
+    asn1_string(27, true, c, s)
+}
+
+/// Encode a `VideotexString` (tag 21). Unlike the other restricted string
+/// types, its content is the CCITT videotex character set rather than
+/// anything `String` can represent, so it's carried as raw bytes and
+/// doesn't go through [`asn1_string`].
+pub(crate) fn asn1_videotex_string(c: ASN1Class, content: &[u8]) -> Vec<u8> {
+    let mut body = content.to_vec();
+    let inttag = BigUint::from_u8(21).unwrap();
+    let mut lenbytes = len(body.len());
+    let mut tagbytes = tag(c, false, &inttag);
+
+    let mut res = Vec::new();
+    res.append(&mut tagbytes);
+    res.append(&mut lenbytes);
+    res.append(&mut body);
+    res
+}
+
+/// Order a SET's already-encoded children into DER canonical order and
+/// concatenate them.
+///
+/// X.690 §11.6 requires the elements of a SET OF to appear sorted by their
+/// full TLV byte representation, comparing octet-by-octet and treating a
+/// shorter value that is a prefix of a longer one as sorting first. The
+/// caller encodes each child to bytes first; this just does the sort and
+/// concatenation step, so the default DER output of a re-encoded
+/// `ASN1Block::Set` matches the bytes it was originally signed/hashed as.
+pub(crate) fn der_set(mut children: Vec<Vec<u8>>) -> Vec<u8> {
+    children.sort();
+
+    let mut res = Vec::new();
+    for mut child in children {
+        res.append(&mut child);
+    }
+    res
+}
+
+/// Encode an `f64` as a full ASN.1 REAL (X.690 §8.5) TLV, universal tag 9.
+///
+/// `0.0` is encoded as empty content; the other special values (`-0.0`,
+/// `±INFINITY`, `NaN`) use the dedicated one-octet special-real forms;
+/// everything else is emitted as a base-2
+/// binary real with scaling factor 0, which is the simplest conforming
+/// encoding.
+pub(crate) fn asn1_real(c: ASN1Class, v: f64) -> Result<Vec<u8>, ASN1EncodeErr> {
+    let mut body = real_content(v);
+    let inttag = BigUint::from_u8(9).unwrap();
+    let mut lenbytes = len(body.len());
+    let mut tagbytes = tag(c, false, &inttag);
+
+    let mut res = Vec::new();
+    res.append(&mut tagbytes);
+    res.append(&mut lenbytes);
+    res.append(&mut body);
+    Ok(res)
+}
+
+fn real_content(v: f64) -> Vec<u8> {
+    if v == 0.0 {
+        return if v.is_sign_negative() {
+            vec![0x43]
+        } else {
+            Vec::new()
+        };
+    }
+    if v.is_nan() {
+        return vec![0x42];
+    }
+    if v.is_infinite() {
+        return vec![if v > 0.0 { 0x40 } else { 0x41 }];
+    }
+
+    let bits = v.abs().to_bits();
+    let raw_exponent = ((bits >> 52) & 0x7ff) as i64;
+    let raw_mantissa = bits & 0x000f_ffff_ffff_ffff;
+    let (mut mantissa, mut exponent) = if raw_exponent == 0 {
+        (raw_mantissa, -1074)
+    } else {
+        (raw_mantissa | (1 << 52), raw_exponent - 1075)
+    };
+    let trailing_zeros = mantissa.trailing_zeros();
+    mantissa >>= trailing_zeros;
+    exponent += i64::from(trailing_zeros);
+
+    let mut mantissa_bytes = mantissa.to_be_bytes().to_vec();
+    while mantissa_bytes.len() > 1 && mantissa_bytes[0] == 0 {
+        mantissa_bytes.remove(0);
+    }
+    let exp_bytes = minimal_twos_complement(exponent);
+
+    let mut first = 0x80u8;
+    if v.is_sign_negative() {
+        first |= 0x40;
+    }
+    let mut res = match exp_bytes.len() {
+        1 => vec![first],
+        2 => vec![first | 0b01],
+        3 => vec![first | 0b10],
+        n => vec![first | 0b11, n as u8],
+    };
+    res.extend_from_slice(&exp_bytes);
+    res.append(&mut mantissa_bytes);
+    res
+}
+
+fn minimal_twos_complement(v: i64) -> Vec<u8> {
+    let mut bytes = v.to_be_bytes().to_vec();
+    while bytes.len() > 1 {
+        let redundant = (bytes[0] == 0x00 && (bytes[1] & 0x80) == 0)
+            || (bytes[0] == 0xff && (bytes[1] & 0x80) != 0);
+        if !redundant {
+            break;
+        }
+        bytes.remove(0);
+    }
+    bytes
+}
+
 pub(crate) fn tag(c: ASN1Class, constructed: bool, t: &BigUint) -> Vec<u8> {
     let cbyte = class(c);
 
@@ -113,3 +277,90 @@ pub(crate) fn len(x: usize) -> Vec<u8> {
         bstr
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn der_set_sorts_children_by_encoded_bytes() {
+        let integer_five = vec![0x02, 0x01, 0x05];
+        let integer_one = vec![0x02, 0x01, 0x01];
+        let boolean_true = vec![0x01, 0x01, 0xff];
+
+        let out = der_set(vec![
+            integer_five.clone(),
+            integer_one.clone(),
+            boolean_true.clone(),
+        ]);
+
+        let mut expected = vec![integer_five, integer_one, boolean_true];
+        expected.sort();
+        let expected: Vec<u8> = expected.into_iter().flatten().collect();
+
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    fn der_set_treats_a_byte_prefix_as_sorting_first() {
+        let prefix = vec![0x01];
+        let longer = vec![0x01, 0x00];
+
+        let out = der_set(vec![longer.clone(), prefix.clone()]);
+
+        assert_eq!(out, [prefix, longer].concat());
+    }
+
+    fn decode_string_like_tlv(bytes: &[u8]) -> crate::asn1_data_types::ASN1Block {
+        use crate::decode::{length, string_like_block, tag, DecodeMode, Length};
+
+        let mut index = 0;
+        let (t, constructed, _class) = tag(bytes, &mut index).unwrap();
+        let content_len = match length(bytes, &mut index, constructed, DecodeMode::Ber).unwrap() {
+            Length::Definite(n) => n,
+            Length::Indefinite => panic!("these string types are never indefinite-length"),
+        };
+        string_like_block(0, t.to_u8().unwrap(), &bytes[index..index + content_len]).unwrap()
+    }
+
+    #[test]
+    fn restricted_string_variants_round_trip() {
+        use crate::asn1_data_types::ASN1Block;
+
+        let text = "hello";
+        let cases = [
+            (
+                asn1_object_descriptor(ASN1Class::Universal, text).unwrap(),
+                ASN1Block::ObjectDescriptor(0, text.to_string()),
+            ),
+            (
+                asn1_graphic_string(ASN1Class::Universal, text).unwrap(),
+                ASN1Block::GraphicString(0, text.to_string()),
+            ),
+            (
+                asn1_visible_string(ASN1Class::Universal, text).unwrap(),
+                ASN1Block::VisibleString(0, text.to_string()),
+            ),
+            (
+                asn1_general_string(ASN1Class::Universal, text).unwrap(),
+                ASN1Block::GeneralString(0, text.to_string()),
+            ),
+        ];
+        for (encoded, expected) in cases {
+            assert_eq!(decode_string_like_tlv(&encoded), expected);
+        }
+    }
+
+    #[test]
+    fn videotex_string_round_trips_as_raw_bytes() {
+        use crate::asn1_data_types::ASN1Block;
+
+        let content = vec![0x01, 0x02, 0xff];
+        let encoded = asn1_videotex_string(ASN1Class::Universal, &content);
+
+        assert_eq!(
+            decode_string_like_tlv(&encoded),
+            ASN1Block::VideotexString(0, content)
+        );
+    }
+}
@@ -19,6 +19,22 @@ pub enum ASN1DecodeErr {
     InvalidDateValue(String),
     #[error("Invalid length of bit string: {0}")]
     InvalidBitStringLength(isize),
+    #[error("Invalid REAL encoding: {0}")]
+    InvalidRealEncoding(String),
+    #[error("Encountered a BER indefinite length on a primitive block")]
+    IndefiniteInPrimitive,
+    #[error("Encountered a BER indefinite length while decoding in strict DER mode")]
+    IndefiniteLengthInDer,
+    #[error("Length field is not in minimal DER form: {0}")]
+    NonMinimalLength(usize),
+    #[error("Invalid dotted-decimal OID string: {0}")]
+    InvalidOIDString(String),
+    #[error("Integer (or enumerated) encoding uses non-minimal padding")]
+    InvalidIntegerPadding,
+    #[error("Invalid boolean value in strict DER mode: {0:#04x}")]
+    InvalidBooleanValue(u8),
+    #[error("SET OF elements are not in DER canonical order")]
+    SetNotInCanonicalOrder,
     /// Not a valid ASN.1 class
     #[error("Invalid class value: {0}")]
     InvalidClass(u8),
@@ -41,4 +57,3 @@ pub enum ASN1EncodeErr {
     #[error("Second value in ASN1 OID is too big.")]
     ObjectIdentVal2TooLarge,
 }
-
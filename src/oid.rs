@@ -1,10 +1,14 @@
 use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
 
 use num_bigint::BigUint;
 use num_traits::{FromPrimitive, ToPrimitive};
 
-use crate::{encode, errors::{ASN1DecodeErr, ASN1EncodeErr}};
-
+use crate::{
+    encode,
+    errors::{ASN1DecodeErr, ASN1EncodeErr},
+};
 
 /// An ASN.1 OID.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -67,6 +71,59 @@ impl OID {
 
         Ok(vec)
     }
+
+    /// Returns the arc at `index`, or `None` if the OID is shorter than that.
+    pub fn arc(&self, index: usize) -> Option<&BigUint> {
+        self.0.get(index)
+    }
+
+    /// Iterate over the arcs, left to right.
+    pub fn iter(&self) -> std::slice::Iter<'_, BigUint> {
+        self.0.iter()
+    }
+}
+
+impl FromStr for OID {
+    type Err = ASN1DecodeErr;
+
+    /// Parse the conventional dotted-decimal representation of an OID,
+    /// e.g. `"1.2.840.113549.1.1.1"`. Requires at least two arcs, and every
+    /// arc must be a non-empty unsigned integer.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = s.split('.').collect();
+
+        if parts.len() < 2 {
+            return Err(ASN1DecodeErr::InvalidOIDString(s.to_string()));
+        }
+
+        let mut arcs = Vec::with_capacity(parts.len());
+        for part in parts {
+            // BigUint::from_str alone would also take a leading '+' or '_'
+            // digit separators.
+            if !part.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(ASN1DecodeErr::InvalidOIDString(s.to_string()));
+            }
+            let arc = BigUint::from_str(part)
+                .map_err(|_| ASN1DecodeErr::InvalidOIDString(s.to_string()))?;
+            arcs.push(arc);
+        }
+
+        Ok(OID::new(arcs))
+    }
+}
+
+impl fmt::Display for OID {
+    /// Format as the conventional dotted-decimal representation, e.g.
+    /// `"1.2.840.113549.1.1.1"`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, arc) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ".")?;
+            }
+            write!(f, "{}", arc)?;
+        }
+        Ok(())
+    }
 }
 
 impl<'a> PartialEq<OID> for &'a OID {
@@ -98,3 +155,46 @@ macro_rules! oid {
         $crate::oid::OID::new(vec![$($crate::BigUint::from($e as u64)),*])
     }};
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_round_trips_through_from_str() {
+        let text = "1.2.840.113549.1.1.1";
+        let parsed = OID::from_str(text).unwrap();
+
+        assert_eq!(parsed.to_string(), text);
+        assert_eq!(OID::from_str(&parsed.to_string()).unwrap(), parsed);
+    }
+
+    #[test]
+    fn from_str_rejects_too_few_arcs_and_empty_parts() {
+        assert_eq!(
+            OID::from_str("1"),
+            Err(ASN1DecodeErr::InvalidOIDString("1".to_string()))
+        );
+        assert_eq!(
+            OID::from_str("1..2"),
+            Err(ASN1DecodeErr::InvalidOIDString("1..2".to_string()))
+        );
+        assert_eq!(
+            OID::from_str("1.2.abc"),
+            Err(ASN1DecodeErr::InvalidOIDString("1.2.abc".to_string()))
+        );
+    }
+
+    #[test]
+    fn arc_and_iter_expose_the_parsed_components() {
+        let oid = OID::from_str("1.2.840").unwrap();
+
+        assert_eq!(oid.arc(0), Some(&BigUint::from(1u8)));
+        assert_eq!(oid.arc(1), Some(&BigUint::from(2u8)));
+        assert_eq!(oid.arc(2), Some(&BigUint::from(840u16)));
+        assert_eq!(oid.arc(3), None);
+
+        let collected: Vec<&BigUint> = oid.iter().collect();
+        assert_eq!(collected, vec![&oid.0[0], &oid.0[1], &oid.0[2]]);
+    }
+}